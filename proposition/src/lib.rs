@@ -0,0 +1,15 @@
+// Implemented by whichever type `proposition!` should build, so this crate
+// can stay generic over the caller's own proposition representation.
+pub trait FromSentence {
+    fn from_sentence(sentence: &str) -> Self;
+}
+
+// Builds a proposition from Rust-like infix syntax, e.g.
+// `proposition!(p & q => r)`. The token tree is stringified and handed to
+// the target type's own parser via `FromSentence`.
+#[macro_export]
+macro_rules! proposition {
+    ($($t:tt)*) => {
+        <_ as $crate::FromSentence>::from_sentence(stringify!($($t)*))
+    };
+}
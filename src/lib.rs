@@ -1,13 +1,20 @@
-use std::collections::{VecDeque, HashMap};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
 use std::mem;
 use std::ops::{BitAnd, BitOr, Not, Shr, Rem};
-use regex::Regex;
+#[cfg(test)]
 use proposition::proposition;
 
-type Model = HashMap<String, bool>;
+pub type Model = HashMap<String, bool>;
+
+// A signed literal: (symbol name, true if unnegated). A clause is the set
+// of literals in one disjunction of the CNF clause set.
+type Literal = (String, bool);
+type Clause = BTreeSet<Literal>;
 
 #[derive(Clone)]
-struct UnaryOp {
+#[cfg_attr(feature = "quickcheck", derive(Debug))]
+pub struct UnaryOp {
     arg: Prop,
 }
 
@@ -20,7 +27,8 @@ impl UnaryOp {
 }
 
 #[derive(Clone)]
-struct BinaryOp {
+#[cfg_attr(feature = "quickcheck", derive(Debug))]
+pub struct BinaryOp {
     lhs: Prop,
     rhs: Prop,
 }
@@ -35,7 +43,8 @@ impl BinaryOp {
 }
 
 #[derive(Clone, Default)]
-enum Prop {
+#[cfg_attr(feature = "quickcheck", derive(Debug))]
+pub enum Prop {
     #[default]
     True,
     False,
@@ -80,7 +89,7 @@ impl Prop {
         Prop::Iff(Box::new(BinaryOp::new(lhs, rhs)))
     }
 
-    fn eval(&self, model: &Model) -> bool {
+    pub fn eval(&self, model: &Model) -> bool {
         match self {
             Prop::True => true,
             Prop::False => false,
@@ -94,6 +103,28 @@ impl Prop {
         }
     }
 
+    // Brute-force semantic equality: true iff `self` and `other` agree on
+    // every assignment over the symbols appearing in either of them.
+    // Only called from the quickcheck properties below, so it's gated the
+    // same way (test + quickcheck) to avoid a dead_code warning whenever
+    // that call site isn't compiled in.
+    #[cfg(all(test, feature = "quickcheck"))]
+    fn equivalent(&self, other: &Prop) -> bool {
+        let mut symbols = self.symbols();
+        for name in other.symbols() {
+            if !symbols.contains(&name) {
+                symbols.push(name);
+            }
+        }
+        (0..(1usize << symbols.len())).all(|i| {
+            let mut model = Model::new();
+            for (bit, name) in symbols.iter().enumerate() {
+                model.insert(name.clone(), (i >> bit) & 1 == 1);
+            }
+            self.eval(&model) == other.eval(&model)
+        })
+    }
+
     fn bicon_elim(&mut self) {
         match self {
             Prop::True | Prop::False | Prop::Symbol(_) => (),
@@ -146,13 +177,13 @@ impl Prop {
                 },
                 // DeMorgan: !(p | q) == (!p & !q)
                 Prop::Or(p) => {
-                    let mut new_lhs = mem::take(&mut p.lhs);
-                    let mut new_rhs = mem::take(&mut p.rhs);
+                    let mut new_lhs = Prop::not(mem::take(&mut p.lhs));
+                    let mut new_rhs = Prop::not(mem::take(&mut p.rhs));
                     // We may have introduced a double negative to the smaller
                     // expressions, so handle them recursively
                     new_lhs.move_not_inward();
                     new_rhs.move_not_inward();
-                    *self = Prop::and(Prop::not(new_lhs), Prop::not(new_rhs));
+                    *self = Prop::and(new_lhs, new_rhs);
                 }
                 Prop::True => *self = Prop::False,
                 Prop::False => *self = Prop::True,
@@ -228,6 +259,182 @@ impl Prop {
         self.split_clause()
     }
 
+    // Distinct symbols in first-appearance order
+    fn symbols(&self) -> Vec<String> {
+        let mut syms = vec![];
+        self.collect_symbols(&mut syms);
+        syms
+    }
+
+    fn collect_symbols(&self, syms: &mut Vec<String>) {
+        match self {
+            Prop::True | Prop::False => (),
+            Prop::Symbol(name) => {
+                if !syms.contains(name) {
+                    syms.push(name.clone());
+                }
+            },
+            Prop::Not(p) => p.arg.collect_symbols(syms),
+            Prop::And(p) | Prop::Or(p) | Prop::Implic(p) | Prop::Iff(p) => {
+                p.lhs.collect_symbols(syms);
+                p.rhs.collect_symbols(syms);
+            },
+        }
+    }
+
+    // Quine-McCluskey: minimal sum-of-products equivalent to self.
+    // Bounded to 20 distinct symbols to keep the minterm table (2^n rows)
+    // in check; a formula with more is returned unsimplified rather than
+    // simplified over a truncated symbol set, which would be silently wrong.
+    pub fn minimize(&self) -> Prop {
+        let symbols = self.symbols();
+        if symbols.len() > 20 {
+            return self.clone();
+        }
+        let n = symbols.len();
+
+        let num_models = 1usize << n;
+        let mut minterms = vec![];
+        for i in 0..num_models {
+            let mut model = Model::new();
+            for (bit, name) in symbols.iter().enumerate() {
+                model.insert(name.clone(), (i >> (n - 1 - bit)) & 1 == 1);
+            }
+            if self.eval(&model) {
+                minterms.push(i);
+            }
+        }
+
+        if minterms.is_empty() {
+            return Prop::False;
+        }
+        if minterms.len() == num_models {
+            return Prop::True;
+        }
+
+        let primes = Self::prime_implicants(&minterms, n);
+        let chosen = Self::cover_minterms(&primes, &minterms);
+
+        let mut disjuncts = chosen.into_iter()
+            .map(|bits| Self::pattern_to_prop(&bits, &symbols));
+        let first = disjuncts.next().unwrap();
+        disjuncts.fold(first, Prop::or)
+    }
+
+    // Bit pattern of `index` over `n` bits, symbol 0 in the most significant position
+    fn bits_of(index: usize, n: usize) -> Vec<Option<bool>> {
+        (0..n).map(|bit| Some((index >> (n - 1 - bit)) & 1 == 1)).collect()
+    }
+
+    // Combine two patterns that differ in exactly one determined bit,
+    // replacing that bit with a don't-care. `None` (a prior don't-care)
+    // must line up in both patterns or they can't be combined.
+    fn combine_bits(a: &[Option<bool>], b: &[Option<bool>]) -> Option<Vec<Option<bool>>> {
+        let mut diff_index = None;
+        for i in 0..a.len() {
+            if a[i] != b[i] {
+                if a[i].is_none() || b[i].is_none() || diff_index.is_some() {
+                    return None;
+                }
+                diff_index = Some(i);
+            }
+        }
+        let idx = diff_index?;
+        let mut combined = a.to_vec();
+        combined[idx] = None;
+        Some(combined)
+    }
+
+    // Repeatedly combine adjacent minterms/implicants until nothing new
+    // combines; whatever never gets used at some level is a prime implicant.
+    fn prime_implicants(minterms: &[usize], n: usize)
+        -> Vec<(Vec<Option<bool>>, HashSet<usize>)> {
+        let mut current: Vec<(Vec<Option<bool>>, HashSet<usize>)> = minterms.iter()
+            .map(|&m| (Self::bits_of(m, n), HashSet::from([m])))
+            .collect();
+        let mut primes = vec![];
+
+        loop {
+            let mut used = vec![false; current.len()];
+            let mut next: Vec<(Vec<Option<bool>>, HashSet<usize>)> = vec![];
+
+            for i in 0..current.len() {
+                for j in (i + 1)..current.len() {
+                    if let Some(bits) = Self::combine_bits(&current[i].0, &current[j].0) {
+                        used[i] = true;
+                        used[j] = true;
+                        if !next.iter().any(|(b, _)| *b == bits) {
+                            let covered = current[i].1.union(&current[j].1)
+                                .cloned().collect();
+                            next.push((bits, covered));
+                        }
+                    }
+                }
+            }
+
+            for (i, term) in current.iter().enumerate() {
+                if !used[i] && !primes.iter().any(|(b, _): &(_, HashSet<usize>)| b == &term.0) {
+                    primes.push(term.clone());
+                }
+            }
+
+            if next.is_empty() {
+                break;
+            }
+            current = next;
+        }
+        primes
+    }
+
+    // Essential prime implicants first, then a greedy cover for what's left
+    fn cover_minterms(
+        primes: &[(Vec<Option<bool>>, HashSet<usize>)],
+        minterms: &[usize],
+    ) -> Vec<Vec<Option<bool>>> {
+        let mut uncovered: HashSet<usize> = minterms.iter().cloned().collect();
+        let mut chosen = HashSet::new();
+
+        for &m in minterms {
+            let mut covering = primes.iter().enumerate()
+                .filter(|(_, (_, covered))| covered.contains(&m))
+                .map(|(i, _)| i);
+            if let (Some(only), None) = (covering.next(), covering.next()) {
+                chosen.insert(only);
+            }
+        }
+        for &i in &chosen {
+            uncovered.retain(|m| !primes[i].1.contains(m));
+        }
+
+        while !uncovered.is_empty() {
+            let best = primes.iter().enumerate()
+                .filter(|(i, _)| !chosen.contains(i))
+                .max_by_key(|(_, (_, covered))| covered.intersection(&uncovered).count());
+            match best {
+                Some((i, (_, covered))) => {
+                    chosen.insert(i);
+                    uncovered.retain(|m| !covered.contains(m));
+                },
+                None => break,
+            }
+        }
+
+        chosen.into_iter().map(|i| primes[i].0.clone()).collect()
+    }
+
+    fn pattern_to_prop(bits: &[Option<bool>], symbols: &[String]) -> Prop {
+        let mut literals = bits.iter().zip(symbols.iter())
+            .filter_map(|(bit, name)| match bit {
+                Some(true) => Some(Prop::symbol(name)),
+                Some(false) => Some(Prop::not(Prop::symbol(name))),
+                None => None,
+            });
+        match literals.next() {
+            Some(first) => literals.fold(first, Prop::and),
+            None => Prop::True,
+        }
+    }
+
     fn print_tree(&self) {
         self.print_layer(0);
     }
@@ -280,115 +487,216 @@ impl Prop {
     }
 }
 
-struct KB {
-    sentences: Vec<Prop>,
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub offset: usize,
+    pub message: String,
 }
 
-impl KB {
-    fn empty() -> Self {
+impl ParseError {
+    fn new(offset: usize, message: &str) -> Self {
         Self {
-            sentences: vec![],
+            offset,
+            message: String::from(message),
         }
     }
+}
 
-    // Just the usual shunting-yard algorithm
-    fn str_to_prop(sentence: &str) -> Result<Prop, &'static str> {
-        let sentence = String::from(sentence);
-        let tokens: Vec<&str> = sentence.split(" ").collect();
-        let mut expr = VecDeque::new();
-        let mut ops = vec![];
-        let err_msg = "invalid sentence";
-        let left_paren_symbol = Regex::new(r"\([[:alpha:]]*").unwrap();
-        let right_paren_symbol = Regex::new(r"[[::alpha::]]*\)").unwrap();
-        for token in tokens.iter() {
-            match *token {
-                "&" => {
-                    ops.push("&");
-                },
-                "|" => {
-                    while let Some(&"&") = ops.last() {
-                        expr.push_back(ops.pop().unwrap());
-                    }
-                    ops.push("|");
-                },
-                "=>" => {
-                    while let Some(&"&") | Some(&"|") = ops.last() {
-                        expr.push_back(ops.pop().unwrap());
-                    }
-                    ops.push("=>");
-                },
-                "<=>" => {
-                    while let Some(&"&") | Some(&"|") | Some(&"=>") 
-                        = ops.last() {
-                        expr.push_back(ops.pop().unwrap());
-                    }
-                    ops.push("<=>");
-                },
-                "(" => ops.push("("),
-                ")" => {
-                    loop {
-                        let op = ops.pop().ok_or_else(|| err_msg)?;
-                        if let "(" = op {
-                            break;
-                        } else {
-                            expr.push_back(op);
-                        }
-                    }
-                },
-                name => {
-                    if let Some(caps) = left_paren_symbol.captures(name) {
-                        ops.push("(");
-                        expr.push_back(&name[1..]);
-                    } else if let Some(caps) 
-                        = right_paren_symbol.captures(name) {
-                        expr.push_back(&name[..name.len()-1]);
-                        loop {
-                            let op = ops.pop().ok_or_else(|| err_msg)?;
-                            if let "(" = op {
-                                break;
-                            } else {
-                                expr.push_back(op);
-                            }
-                        }
-                    } else {
-                        expr.push_back(name);
-                    }
-                },
-            }
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    LParen,
+    RParen,
+    Not,
+    And,
+    Or,
+    Implies,
+    Iff,
+    True,
+    False,
+    Ident(String),
+}
+
+// Scans `sentence` into `(token, byte offset)` pairs, tolerant of
+// whitespace anywhere between tokens
+pub fn tokenize(sentence: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let bytes = sentence.as_bytes();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push((Token::LParen, i));
+                i += 1;
+            },
+            ')' => {
+                tokens.push((Token::RParen, i));
+                i += 1;
+            },
+            '&' => {
+                tokens.push((Token::And, i));
+                i += 1;
+            },
+            '|' => {
+                tokens.push((Token::Or, i));
+                i += 1;
+            },
+            '~' | '!' => {
+                tokens.push((Token::Not, i));
+                i += 1;
+            },
+            '=' if bytes.get(i + 1) == Some(&b'>') => {
+                tokens.push((Token::Implies, i));
+                i += 2;
+            },
+            '<' if bytes.get(i + 1) == Some(&b'=') && bytes.get(i + 2) == Some(&b'>') => {
+                tokens.push((Token::Iff, i));
+                i += 3;
+            },
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < bytes.len()
+                    && ((bytes[i] as char).is_alphanumeric() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                tokens.push((match &sentence[start..i] {
+                    "True" => Token::True,
+                    "False" => Token::False,
+                    name => Token::Ident(String::from(name)),
+                }, start));
+            },
+            c => return Err(ParseError::new(i, &format!("unexpected character '{}'", c))),
+        }
+    }
+    Ok(tokens)
+}
+
+// Recursive-descent parser over the token stream. Precedence, loosest to
+// tightest: `<=>`, `=>` (right-associative), `|`, `&`, unary `~`/`!`.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end_offset: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<(Token, usize)>, end_offset: usize) -> Self {
+        Self {
+            tokens,
+            pos: 0,
+            end_offset,
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_offset(&self) -> usize {
+        self.tokens.get(self.pos).map(|&(_, offset)| offset).unwrap_or(self.end_offset)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(token, _)| token.clone());
+        if token.is_some() {
+            self.pos += 1;
         }
-        while !ops.is_empty() {
-            expr.push_back(ops.pop().unwrap());
+        token
+    }
+
+    fn parse_iff(&mut self) -> Result<Prop, ParseError> {
+        let mut lhs = self.parse_implic()?;
+        while let Some(Token::Iff) = self.peek() {
+            self.advance();
+            lhs = Prop::iff(lhs, self.parse_implic()?);
         }
+        Ok(lhs)
+    }
 
-        let mut stack = vec![];
-        while !expr.is_empty() {
-            match expr.pop_front().unwrap() {
-                "&" => {
-                    let rhs = stack.pop().ok_or_else(|| err_msg)?;
-                    let lhs = stack.pop().ok_or_else(|| err_msg)?;
-                    stack.push(Prop::and(lhs, rhs));
+    fn parse_implic(&mut self) -> Result<Prop, ParseError> {
+        let lhs = self.parse_or()?;
+        if let Some(Token::Implies) = self.peek() {
+            self.advance();
+            // Right-associative: p => q => r means p => (q => r)
+            Ok(Prop::implic(lhs, self.parse_implic()?))
+        } else {
+            Ok(lhs)
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Prop, ParseError> {
+        let mut lhs = self.parse_and()?;
+        while let Some(Token::Or) = self.peek() {
+            self.advance();
+            lhs = Prop::or(lhs, self.parse_and()?);
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Prop, ParseError> {
+        let mut lhs = self.parse_unary()?;
+        while let Some(Token::And) = self.peek() {
+            self.advance();
+            lhs = Prop::and(lhs, self.parse_unary()?);
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Prop, ParseError> {
+        if let Some(Token::Not) = self.peek() {
+            self.advance();
+            Ok(Prop::not(self.parse_unary()?))
+        } else {
+            self.parse_atom()
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Prop, ParseError> {
+        let offset = self.peek_offset();
+        match self.advance() {
+            Some(Token::True) => Ok(Prop::true_literal()),
+            Some(Token::False) => Ok(Prop::false_literal()),
+            Some(Token::Ident(name)) => Ok(Prop::symbol(&name)),
+            Some(Token::LParen) => {
+                let inner = self.parse_iff()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(ParseError::new(offset, "unbalanced parenthesis")),
                 }
-                "|" => {
-                    let rhs = stack.pop().ok_or_else(|| err_msg)?;
-                    let lhs = stack.pop().ok_or_else(|| err_msg)?;
-                    stack.push(Prop::or(lhs, rhs));
-                },
-                "=>" => {
-                    let rhs = stack.pop().ok_or_else(|| err_msg)?;
-                    let lhs = stack.pop().ok_or_else(|| err_msg)?;
-                    stack.push(Prop::implic(lhs, rhs));
-                },
-                "<=>" => {
-                    let rhs = stack.pop().ok_or_else(|| err_msg)?;
-                    let lhs = stack.pop().ok_or_else(|| err_msg)?;
-                    stack.push(Prop::iff(lhs, rhs));
-                },
-                "True" => stack.push(Prop::true_literal()),
-                "False" => stack.push(Prop::false_literal()),
-                name => stack.push(Prop::symbol(name)),
-            }
+            },
+            Some(_) | None => Err(ParseError::new(offset, "missing operand")),
+        }
+    }
+}
+
+pub struct KB {
+    sentences: Vec<Prop>,
+}
+
+impl KB {
+    pub fn empty() -> Self {
+        Self {
+            sentences: vec![],
+        }
+    }
+
+    pub fn str_to_prop(sentence: &str) -> Result<Prop, ParseError> {
+        let tokens = tokenize(sentence)?;
+        let mut parser = Parser::new(tokens, sentence.len());
+        let prop = parser.parse_iff()?;
+        match parser.peek() {
+            None => Ok(prop),
+            Some(_) => Err(ParseError::new(parser.peek_offset(), "unexpected token")),
         }
-        stack.pop().ok_or_else(|| err_msg)
     }
 
     // Split nested ANDs into separate clauses
@@ -414,10 +722,310 @@ impl KB {
         self.sentences = new_sentences;
     }
 
-    fn tell(&mut self, mut prop: Prop) -> Result<(), &'static str> {
+    pub fn tell(&mut self, mut prop: Prop) -> Result<(), &'static str> {
         self.sentences.append(&mut prop.cnf());
         Ok(())
     }
+
+    pub fn print_clauses(&self) {
+        for clause in self.sentences.iter() {
+            clause.print_tree();
+        }
+    }
+
+    // A CNF clause (an Or of literals, as produced by Prop::cnf) as a set of
+    // signed literals. `cnf()` doesn't simplify constants, so a disjunct can
+    // still be `True` (the whole clause is then trivially satisfied, so it
+    // contributes no constraint -- `None`) or `False` (that disjunct just
+    // drops out; a clause that's entirely `False` becomes the empty clause).
+    fn prop_to_clause(prop: &Prop) -> Option<Clause> {
+        let mut literals = Clause::new();
+        if Self::collect_literals(prop, &mut literals) {
+            None
+        } else {
+            Some(literals)
+        }
+    }
+
+    // Returns true if the clause is trivially satisfied by a `True` disjunct
+    fn collect_literals(prop: &Prop, literals: &mut Clause) -> bool {
+        match prop {
+            Prop::True => true,
+            Prop::False => false,
+            Prop::Symbol(name) => {
+                literals.insert((name.clone(), true));
+                false
+            },
+            Prop::Not(p) => match &p.arg {
+                Prop::Symbol(name) => {
+                    literals.insert((name.clone(), false));
+                    false
+                },
+                _ => panic!("clause is not in CNF: negation of a non-symbol"),
+            },
+            Prop::Or(p) => {
+                let lhs_trivial = Self::collect_literals(&p.lhs, literals);
+                let rhs_trivial = Self::collect_literals(&p.rhs, literals);
+                lhs_trivial || rhs_trivial
+            },
+            _ => panic!("clause is not in CNF"),
+        }
+    }
+
+    fn is_tautological(clause: &Clause) -> bool {
+        clause.iter().any(|(name, polarity)| clause.contains(&(name.clone(), !polarity)))
+    }
+
+    // All ways to resolve `a` and `b` on a complementary pair of literals,
+    // dropping any resolvent that is itself tautological
+    fn resolve(a: &Clause, b: &Clause) -> Vec<Clause> {
+        a.iter()
+            .filter_map(|lit| {
+                let complement = (lit.0.clone(), !lit.1);
+                if !b.contains(&complement) {
+                    return None;
+                }
+                let mut resolvent: Clause = a.iter().filter(|l| *l != lit).cloned().collect();
+                resolvent.extend(b.iter().filter(|l| *l != &complement).cloned());
+                if Self::is_tautological(&resolvent) {
+                    None
+                } else {
+                    Some(resolvent)
+                }
+            })
+            .collect()
+    }
+
+    // Resolution refutation: true iff the empty clause is derivable
+    fn resolution_closure(mut clauses: Vec<Clause>) -> bool {
+        let mut seen: HashSet<Clause> = clauses.iter().cloned().collect();
+        if seen.contains(&Clause::new()) {
+            return true;
+        }
+        loop {
+            let mut new_clauses = vec![];
+            for i in 0..clauses.len() {
+                for j in (i + 1)..clauses.len() {
+                    for resolvent in Self::resolve(&clauses[i], &clauses[j]) {
+                        if resolvent.is_empty() {
+                            return true;
+                        }
+                        if seen.insert(resolvent.clone()) {
+                            new_clauses.push(resolvent);
+                        }
+                    }
+                }
+            }
+            if new_clauses.is_empty() {
+                return false;
+            }
+            clauses.extend(new_clauses);
+        }
+    }
+
+    // Entailment via resolution refutation: tell + !query is unsatisfiable
+    // iff tell entails query
+    pub fn ask(&self, query: Prop) -> bool {
+        let mut clauses: Vec<Clause> = self.sentences.iter()
+            .filter_map(Self::prop_to_clause)
+            .collect();
+        for clause in Prop::not(query).cnf() {
+            if let Some(clause) = Self::prop_to_clause(&clause) {
+                clauses.push(clause);
+            }
+        }
+        Self::resolution_closure(clauses)
+    }
+
+    // Remove clauses already satisfied by `name = polarity`, and drop the
+    // now-false literal from the rest
+    fn assign(clauses: Vec<Clause>, name: &str, polarity: bool) -> Vec<Clause> {
+        clauses.into_iter()
+            .filter(|clause| !clause.contains(&(name.to_string(), polarity)))
+            .map(|mut clause| {
+                clause.remove(&(name.to_string(), !polarity));
+                clause
+            })
+            .collect()
+    }
+
+    fn dpll(mut clauses: Vec<Clause>, model: &mut Model) -> bool {
+        // Unit propagation
+        loop {
+            if clauses.iter().any(Clause::is_empty) {
+                return false;
+            }
+            let unit = clauses.iter().find(|c| c.len() == 1)
+                .map(|c| c.iter().next().unwrap().clone());
+            match unit {
+                Some((name, polarity)) => {
+                    model.insert(name.clone(), polarity);
+                    clauses = Self::assign(clauses, &name, polarity);
+                },
+                None => break,
+            }
+        }
+        if clauses.is_empty() {
+            return true;
+        }
+
+        // Pure literal elimination: a symbol appearing with only one
+        // polarity across every remaining clause can be fixed to satisfy it
+        let mut polarities: HashMap<String, Option<bool>> = HashMap::new();
+        for (name, polarity) in clauses.iter().flatten() {
+            polarities.entry(name.clone())
+                .and_modify(|seen| if *seen != Some(*polarity) { *seen = None; })
+                .or_insert(Some(*polarity));
+        }
+        let pure: Vec<(String, bool)> = polarities.into_iter()
+            .filter_map(|(name, polarity)| polarity.map(|p| (name, p)))
+            .collect();
+        if !pure.is_empty() {
+            for (name, polarity) in &pure {
+                model.insert(name.clone(), *polarity);
+                clauses = Self::assign(clauses, name, *polarity);
+            }
+            return Self::dpll(clauses, model);
+        }
+
+        // Branch on an unassigned symbol, backtracking on conflict
+        let symbol = clauses.iter().flatten().next().map(|(name, _)| name.clone());
+        match symbol {
+            None => true,
+            Some(symbol) => [true, false].into_iter().any(|polarity| {
+                let mut branch_model = model.clone();
+                branch_model.insert(symbol.clone(), polarity);
+                if Self::dpll(Self::assign(clauses.clone(), &symbol, polarity), &mut branch_model) {
+                    *model = branch_model;
+                    true
+                } else {
+                    false
+                }
+            }),
+        }
+    }
+
+    // DPLL satisfiability: Some(model) satisfying every told sentence, or
+    // None if the knowledge base is unsatisfiable
+    pub fn is_satisfiable(&self) -> Option<Model> {
+        let clauses: Vec<Clause> = self.sentences.iter()
+            .filter_map(Self::prop_to_clause)
+            .collect();
+        let mut model = Model::new();
+        if Self::dpll(clauses, &mut model) {
+            Some(model)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl Prop {
+    const ARBITRARY_SYMBOLS: [&'static str; 6] = ["a", "b", "c", "d", "e", "f"];
+    const ARBITRARY_MAX_DEPTH: usize = 4;
+
+    fn arbitrary_at_depth(g: &mut quickcheck::Gen, depth: usize) -> Prop {
+        use quickcheck::Arbitrary;
+        if depth == 0 {
+            return Self::arbitrary_leaf(g);
+        }
+        match u8::arbitrary(g) % 8 {
+            0 => Prop::True,
+            1 => Prop::False,
+            2 => Prop::symbol(g.choose(&Self::ARBITRARY_SYMBOLS).unwrap()),
+            3 => Prop::not(Self::arbitrary_at_depth(g, depth - 1)),
+            4 => Prop::and(
+                Self::arbitrary_at_depth(g, depth - 1),
+                Self::arbitrary_at_depth(g, depth - 1)),
+            5 => Prop::or(
+                Self::arbitrary_at_depth(g, depth - 1),
+                Self::arbitrary_at_depth(g, depth - 1)),
+            6 => Prop::implic(
+                Self::arbitrary_at_depth(g, depth - 1),
+                Self::arbitrary_at_depth(g, depth - 1)),
+            _ => Prop::iff(
+                Self::arbitrary_at_depth(g, depth - 1),
+                Self::arbitrary_at_depth(g, depth - 1)),
+        }
+    }
+
+    fn arbitrary_leaf(g: &mut quickcheck::Gen) -> Prop {
+        use quickcheck::Arbitrary;
+        match u8::arbitrary(g) % 3 {
+            0 => Prop::True,
+            1 => Prop::False,
+            _ => Prop::symbol(g.choose(&Self::ARBITRARY_SYMBOLS).unwrap()),
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Prop {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::arbitrary_at_depth(g, Self::ARBITRARY_MAX_DEPTH)
+    }
+
+    // Shrink to the immediate children so a failing case minimizes to the
+    // smallest subtree that still reproduces the failure
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            Prop::True | Prop::False | Prop::Symbol(_) => Box::new(std::iter::empty()),
+            Prop::Not(p) => Box::new(std::iter::once(p.arg.clone())),
+            Prop::And(p) | Prop::Or(p) | Prop::Implic(p) | Prop::Iff(p) =>
+                Box::new(vec![p.lhs.clone(), p.rhs.clone()].into_iter()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "quickcheck"))]
+quickcheck::quickcheck! {
+    fn bicon_elim_is_equivalent(p: Prop) -> bool {
+        let mut q = p.clone();
+        q.bicon_elim();
+        p.equivalent(&q)
+    }
+
+    fn implic_elim_is_equivalent(p: Prop) -> bool {
+        let mut q = p.clone();
+        q.implic_elim();
+        p.equivalent(&q)
+    }
+
+    fn move_not_inward_is_equivalent(p: Prop) -> bool {
+        let mut q = p.clone();
+        q.bicon_elim();
+        q.implic_elim();
+        q.move_not_inward();
+        p.equivalent(&q)
+    }
+
+    fn distribute_or_over_and_is_equivalent(p: Prop) -> bool {
+        let mut q = p.clone();
+        q.bicon_elim();
+        q.implic_elim();
+        q.move_not_inward();
+        q.distribute_or_over_and();
+        p.equivalent(&q)
+    }
+
+    fn cnf_is_equivalent(p: Prop) -> bool {
+        let clauses = p.clone().cnf();
+        let conjunction = clauses.into_iter()
+            .reduce(Prop::and)
+            .unwrap_or(Prop::True);
+        p.equivalent(&conjunction)
+    }
+}
+
+// Lets the `proposition!` macro build a `Prop` directly from its stringified
+// token tree, so tests can write e.g. `proposition!(p & q => r)` instead of
+// `KB::str_to_prop("p & q => r").unwrap()`.
+#[cfg(test)]
+impl proposition::FromSentence for Prop {
+    fn from_sentence(sentence: &str) -> Self {
+        KB::str_to_prop(sentence).unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -434,4 +1042,102 @@ mod tests {
             prop.print_tree();
         }
     }
+
+    #[test]
+    fn minimize_known_reduction() {
+        // (a & b) | (a & !b) == a
+        let p: Prop = proposition!((a & b) | (a & !b));
+        let min = p.minimize();
+        assert!(matches!(min, Prop::Symbol(ref name) if name == "a"));
+        let mut model = Model::new();
+        model.insert(String::from("a"), true);
+        model.insert(String::from("b"), false);
+        assert_eq!(min.eval(&model), p.eval(&model));
+        model.insert(String::from("a"), false);
+        model.insert(String::from("b"), true);
+        assert_eq!(min.eval(&model), p.eval(&model));
+    }
+
+    #[test]
+    fn minimize_unsatisfiable() {
+        let p: Prop = proposition!(a & !a);
+        assert!(matches!(p.minimize(), Prop::False));
+    }
+
+    #[test]
+    fn minimize_tautology() {
+        let p: Prop = proposition!(a | !a);
+        assert!(matches!(p.minimize(), Prop::True));
+    }
+
+    #[test]
+    fn minimize_over_symbol_cap_returns_unsimplified() {
+        let mut p = Prop::symbol("s0");
+        for i in 1..21 {
+            p = Prop::and(p, Prop::symbol(&format!("s{i}")));
+        }
+        let mut model = Model::new();
+        for i in 0..21 {
+            model.insert(format!("s{i}"), true);
+        }
+        let min = p.minimize();
+        assert_eq!(min.eval(&model), p.eval(&model));
+    }
+
+    #[test]
+    fn parse_precedence_and_not() {
+        let p = KB::str_to_prop("(p&q)").unwrap();
+        assert!(matches!(p, Prop::And(_)));
+
+        let p = KB::str_to_prop("!p").unwrap();
+        assert!(matches!(p, Prop::Not(_)));
+    }
+
+    #[test]
+    fn parse_unbalanced_paren_reports_offset() {
+        // Error is anchored at the unmatched '(' itself
+        match KB::str_to_prop("(p & q") {
+            Err(err) => assert_eq!(err.offset, 0),
+            Ok(_) => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn ask_modus_ponens() {
+        let mut kb = KB::empty();
+        kb.tell(proposition!(p => q));
+        kb.tell(proposition!(p));
+        assert!(kb.ask(proposition!(q)));
+        assert!(!kb.ask(proposition!(!q)));
+    }
+
+    #[test]
+    fn ask_non_entailment() {
+        let mut kb = KB::empty();
+        kb.tell(proposition!(p | q));
+        assert!(!kb.ask(proposition!(p)));
+    }
+
+    #[test]
+    fn is_satisfiable_sat_and_unsat() {
+        let mut sat_kb = KB::empty();
+        sat_kb.tell(proposition!(p | q));
+        assert!(sat_kb.is_satisfiable().is_some());
+
+        let mut unsat_kb = KB::empty();
+        unsat_kb.tell(proposition!(p));
+        unsat_kb.tell(proposition!(!p));
+        assert!(unsat_kb.is_satisfiable().is_none());
+    }
+
+    #[test]
+    fn tell_constant_in_disjunction_does_not_panic() {
+        let mut kb = KB::empty();
+        kb.tell(KB::str_to_prop("p | True").unwrap());
+        assert!(kb.is_satisfiable().is_some());
+
+        let mut kb = KB::empty();
+        kb.tell(KB::str_to_prop("p | False").unwrap());
+        assert!(kb.ask(proposition!(p)));
+    }
 }
@@ -0,0 +1,165 @@
+use std::fs;
+use std::io::Write;
+
+use logic::{tokenize, KB, Model, ParseError, Token};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Editor, Helper};
+
+// Rejects a line as incomplete (rather than submitting it) when it leaves a
+// paren open or ends on an operator (binary, or a negation) awaiting an
+// operand; a surplus `)` is submitted so the parser can report it
+struct SentenceValidator;
+
+impl SentenceValidator {
+    fn fragment(line: &str) -> &str {
+        line.strip_prefix("tell ")
+            .or_else(|| line.strip_prefix("eval "))
+            .unwrap_or(line)
+    }
+
+    fn is_incomplete(fragment: &str) -> bool {
+        let tokens = match tokenize(fragment) {
+            Ok(tokens) => tokens,
+            Err(_) => return false, // let the parser report the real error
+        };
+        let mut depth = 0i32;
+        for (token, _) in &tokens {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                _ => (),
+            }
+            if depth < 0 {
+                return false;
+            }
+        }
+        if depth > 0 {
+            return true;
+        }
+        matches!(
+            tokens.last(),
+            Some((Token::And | Token::Or | Token::Implies | Token::Iff | Token::Not, _))
+        )
+    }
+}
+
+impl Validator for SentenceValidator {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        if Self::is_incomplete(Self::fragment(ctx.input())) {
+            Ok(ValidationResult::Incomplete)
+        } else {
+            Ok(ValidationResult::Valid(None))
+        }
+    }
+}
+
+impl Helper for SentenceValidator {}
+impl Completer for SentenceValidator {
+    type Candidate = String;
+}
+impl Hinter for SentenceValidator {
+    type Hint = String;
+}
+impl Highlighter for SentenceValidator {}
+
+fn print_parse_error(sentence: &str, err: &ParseError) {
+    eprintln!("{}", err);
+    eprintln!("{}", sentence);
+    eprintln!("{}^", " ".repeat(err.offset));
+}
+
+fn tell(kb: &mut KB, sentence: &str) {
+    match KB::str_to_prop(sentence) {
+        Ok(prop) => {
+            let _ = kb.tell(prop);
+        },
+        Err(err) => print_parse_error(sentence, &err),
+    }
+}
+
+fn eval(sentence: &str) {
+    let prop = match KB::str_to_prop(sentence) {
+        Ok(prop) => prop,
+        Err(err) => {
+            print_parse_error(sentence, &err);
+            return;
+        },
+    };
+
+    println!("Enter truth values as `symbol=true`/`symbol=false`, blank line to evaluate:");
+    let mut model = Model::new();
+    loop {
+        print!("  ");
+        std::io::stdout().flush().ok();
+        let mut line = String::new();
+        if std::io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+        match line.split_once('=') {
+            Some((name, value)) => {
+                model.insert(String::from(name.trim()), value.trim() == "true");
+            },
+            None => eprintln!("expected `symbol=true` or `symbol=false`"),
+        }
+    }
+    println!("{}", prop.eval(&model));
+}
+
+fn load(kb: &mut KB, path: &str) {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            for line in contents.lines() {
+                let line = line.trim();
+                if !line.is_empty() {
+                    tell(kb, line);
+                }
+            }
+        },
+        Err(err) => eprintln!("could not read {}: {}", path, err),
+    }
+}
+
+fn main() -> rustyline::Result<()> {
+    let mut kb = KB::empty();
+    let mut rl: Editor<SentenceValidator, DefaultHistory> = Editor::new()?;
+    rl.set_helper(Some(SentenceValidator));
+
+    loop {
+        match rl.readline("logic> ") {
+            Ok(line) => {
+                let _ = rl.add_history_entry(line.as_str());
+                let line = line.trim();
+                if let Some(sentence) = line.strip_prefix("tell ") {
+                    tell(&mut kb, sentence);
+                } else if let Some(sentence) = line.strip_prefix("eval ") {
+                    eval(sentence);
+                } else if let Some(path) = line.strip_prefix("load ") {
+                    load(&mut kb, path);
+                } else if line == "clauses" {
+                    kb.print_clauses();
+                } else if line == "reset" {
+                    kb = KB::empty();
+                } else if line.is_empty() {
+                    continue;
+                } else {
+                    eprintln!("unknown command: {}", line);
+                }
+            },
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                break;
+            },
+        }
+    }
+    Ok(())
+}